@@ -0,0 +1,103 @@
+//! Context-sensitive (IL-system) Lindenmayer Systems.
+//!
+//! The plain [`ProductionRule`](../type.ProductionRule.html) rewrites a
+//! symbol in isolation, which makes the grammar context-free. Some systems
+//! — notably models of cell interaction such as the signal-propagation
+//! examples in *Anabaena catenula* — need a rule to see the symbols on
+//! either side of the one being rewritten, so that a symbol only rewrites
+//! when adjacent to a particular neighbor.
+//!
+//! A [`ContextRule`] receives the symbol's left and right neighbors as
+//! `Option<&T>` (`None` at the ends of the string) alongside the symbol
+//! itself.
+
+use crate::generations::{self, Generations};
+
+/// A context-sensitive production rule. The first and third arguments are
+/// the left and right neighbors of the symbol being rewritten, or `None` at
+/// the boundaries of the current state.
+pub type ContextRule<T> = fn(Option<&T>, T, Option<&T>) -> Vec<T>;
+
+/// A Lindenmayer System whose rule may inspect a symbol's left and right
+/// neighbors in the current state, rather than just the symbol itself.
+pub struct ContextLSystemType<T: Clone> {
+    axiom: Vec<T>,
+    rules: ContextRule<T>,
+}
+
+impl<T: Clone + 'static> ContextLSystemType<T> {
+    pub fn new(axiom: Vec<T>, rules: ContextRule<T>) -> ContextLSystemType<T> {
+        ContextLSystemType { axiom, rules }
+    }
+
+    /// Returns an iterator that yields successive iterations of the
+    /// L-System, starting with the axiom itself (`n = 0`). The iterator
+    /// never terminates, so any loop over it must be broken manually.
+    pub fn iter(&self) -> ContextLSystemIterator<T> {
+        let rules = self.rules;
+        generations::generations(self.axiom.clone(), move |state: &Vec<T>| apply_rules(state, rules))
+    }
+
+    /// Directly returns generation `n` of the L-System, i.e. the axiom
+    /// rewritten `n` times.
+    pub fn nth_iteration(&self, n: usize) -> Vec<T> {
+        generations::nth_generation(self.iter(), n)
+    }
+
+    /// Returns generations `0` through `n` inclusive, as a `Vec` of `n + 1`
+    /// states.
+    pub fn take_iterations(&self, n: usize) -> Vec<Vec<T>> {
+        generations::take_generations(self.iter(), n)
+    }
+}
+
+/// An iterator over a [`ContextLSystemType`], where each successive
+/// iteration walks the current state and passes each symbol, together with
+/// references to its previous and next neighbors, to the context rule
+/// before concatenating the results.
+pub type ContextLSystemIterator<T> = Generations<Vec<T>>;
+
+fn apply_rules<T: Clone>(state: &[T], rules: ContextRule<T>) -> Vec<T> {
+    let mut new_state: Vec<T> = Vec::new();
+    for (index, element) in state.iter().cloned().enumerate() {
+        let prev = if index == 0 { None } else { state.get(index - 1) };
+        let next = state.get(index + 1);
+        new_state.extend(rules(prev, element, next));
+    }
+    new_state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Doubles a symbol only when its left neighbor is 'a'; the first symbol's
+    // left neighbor is `None`, so it is never doubled. The right neighbor is
+    // ignored.
+    fn double_after_a(prev: Option<&char>, symbol: char, _next: Option<&char>) -> Vec<char> {
+        if prev == Some(&'a') {
+            vec![symbol, symbol]
+        } else {
+            vec![symbol]
+        }
+    }
+
+    #[test]
+    fn neighbor_lookup_sees_none_at_boundaries() {
+        let lsystem = ContextLSystemType::new(vec!['a', 'b', 'a'], double_after_a);
+
+        // 'a' at index 0 has no left neighbor, so it is left alone; 'b' has
+        // left neighbor 'a' and is doubled; the trailing 'a' has left
+        // neighbor 'b' and is left alone.
+        assert_eq!(lsystem.nth_iteration(1), vec!['a', 'b', 'b', 'a']);
+    }
+
+    #[test]
+    fn nth_iteration_matches_take_iterations_tail() {
+        let lsystem = ContextLSystemType::new(vec!['a', 'b'], double_after_a);
+
+        let generations = lsystem.take_iterations(3);
+        assert_eq!(generations.len(), 4);
+        assert_eq!(generations[3], lsystem.nth_iteration(3));
+    }
+}
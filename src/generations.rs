@@ -0,0 +1,39 @@
+//! Shared machinery for building the infinite, axiom-seeded iterators used
+//! by every L-System variant in this crate, on top of
+//! [`std::iter::successors`], plus the `nth_iteration`/`take_iterations`
+//! ergonomics layered on top of any such iterator.
+//!
+//! This is internal to the crate: each public `*LSystemType` owns its own
+//! `iter()`/`nth_iteration()`/`take_iterations()` methods with doc comments
+//! tailored to that type, but all of them delegate the actual iterator
+//! construction here instead of hand-rolling a `zeroth`-flag state machine.
+
+use std::iter;
+
+/// The boxed step closure behind a [`Generations`] iterator.
+type Step<S> = Box<dyn FnMut(&S) -> Option<S>>;
+
+/// An iterator that starts at generation `0` (the axiom) and applies a step
+/// function to produce each subsequent generation.
+pub(crate) type Generations<S> = iter::Successors<S, Step<S>>;
+
+/// Builds a [`Generations`] iterator seeded with `axiom`, applying `step` to
+/// the previous generation to produce each next one.
+pub(crate) fn generations<S, F>(axiom: S, mut step: F) -> Generations<S>
+where
+    F: FnMut(&S) -> S + 'static,
+    S: 'static,
+{
+    let boxed: Step<S> = Box::new(move |state: &S| Some(step(state)));
+    iter::successors(Some(axiom), boxed)
+}
+
+/// Directly returns generation `n` from a [`Generations`] iterator.
+pub(crate) fn nth_generation<S>(mut iter: Generations<S>, n: usize) -> S {
+    iter.nth(n).expect("generations iterator is infinite")
+}
+
+/// Returns generations `0..=n` from a [`Generations`] iterator.
+pub(crate) fn take_generations<S>(iter: Generations<S>, n: usize) -> Vec<S> {
+    iter.take(n + 1).collect()
+}
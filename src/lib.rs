@@ -44,7 +44,7 @@
 //!
 //! ```rust
 //! use lsystem::LSystemType;
-//! # #[deriving(Clone, Show, Eq, PartialEq)]
+//! # #[derive(Clone, Debug, Eq, PartialEq)]
 //! # enum Algae { A, B }
 //! # fn algae_rule(input: Algae) -> Vec<Algae> {
 //! #     match input {
@@ -55,15 +55,26 @@
 //!
 //! let algae_lsystem = LSystemType::new(vec!(Algae::B), algae_rule);
 //!
-//! // The iter() method returns a normal Rust iterator, so to get the fifth
-//! // item (which is the n = 4 iteration) we use the following idiom:
-//! let algae_lsystem_n4 = algae_lsystem.iter().skip(4).next().unwrap();
+//! // nth_iteration(n) directly returns generation n, so the fifth iteration
+//! // (n = 4) needs no skip/next dance:
+//! let algae_lsystem_n4 = algae_lsystem.nth_iteration(4);
 //!
 //! // And confirm that it matches Lindenmayer's fifth iteration.
 //! assert_eq!(algae_lsystem_n4,
 //!            vec!(Algae::A, Algae::B, Algae::A, Algae::A, Algae::B))
 //! ```
 
+use std::rc::Rc;
+
+mod generations;
+pub mod context;
+pub mod parametric;
+pub mod stochastic;
+pub mod strings;
+pub mod turtle;
+
+use generations::Generations;
+
 /// The function used as a part of the definition of the L-System must match
 /// this type definition.
 pub type ProductionRule<T> = fn(T) -> Vec<T>;
@@ -93,53 +104,108 @@ pub type ProductionRule<T> = fn(T) -> Vec<T>;
 /// The [`iter()`](#method.iter) method returns a Rust iterator that yields
 /// successive iterations of the L-System. This allows very idiomatic handling
 /// of iteration, but be warned: the iterator will never be exhausted, so any
-/// loops must be broken manually.
+/// loops must be broken manually. For the common cases of wanting a single
+/// generation or a bounded run of them, see
+/// [`nth_iteration()`](#method.nth_iteration) and
+/// [`take_iterations()`](#method.take_iterations).
 pub struct LSystemType<T: Clone> {
     axiom: Vec<T>,
-    rules: ProductionRule<T>
+    rules: Rc<dyn Fn(T) -> Vec<T>>
 }
 
-impl<T: Clone> LSystemType<T> {
+impl<T: Clone + 'static> LSystemType<T> {
     pub fn new(axiom: Vec<T>, rules: ProductionRule<T>) -> LSystemType<T> {
-        LSystemType { axiom: axiom, rules: rules }
+        LSystemType { axiom, rules: Rc::new(rules) }
     }
 
+    /// Builds an L-System from a rule *closure* rather than a bare
+    /// [`ProductionRule`] function pointer, so the rule may close over its
+    /// own per-instance state (a compiled lookup table, for example)
+    /// instead of relying on global mutable state shared by every
+    /// `LSystemType` built from a plain `fn`. Builders like
+    /// [`strings::from_rules`] exist precisely so no compiled rule table
+    /// ever needs to live in a `thread_local!` (or other shared) slot read
+    /// back by a bare `fn` — that pattern silently corrupts every other
+    /// system sharing the slot instead of failing to compile.
+    pub fn from_boxed_rule<F: Fn(T) -> Vec<T> + 'static>(axiom: Vec<T>, rules: F) -> LSystemType<T> {
+        LSystemType { axiom, rules: Rc::new(rules) }
+    }
+
+    /// Returns an iterator that yields successive iterations of the
+    /// L-System, starting with the axiom itself (`n = 0`). The iterator
+    /// never terminates, so any loop over it must be broken manually.
     pub fn iter(&self) -> LSystemIterator<T> {
-        LSystemIterator {
-            current_state: self.axiom.clone(),
-            rules: self.rules,
-            zeroth: true
-        }
+        let rules = self.rules.clone();
+        generations::generations(self.axiom.clone(), move |state: &Vec<T>| apply_rules(state, &*rules))
+    }
+
+    /// Directly returns generation `n` of the L-System, i.e. the axiom
+    /// rewritten `n` times.
+    pub fn nth_iteration(&self, n: usize) -> Vec<T> {
+        generations::nth_generation(self.iter(), n)
+    }
+
+    /// Returns generations `0` through `n` inclusive, as a `Vec` of `n + 1`
+    /// states.
+    pub fn take_iterations(&self, n: usize) -> Vec<Vec<T>> {
+        generations::take_generations(self.iter(), n)
     }
 }
 
-/// Defines an iterator over an L-System, where each successive iteration
-/// applies a series of rules to the current axiom to produce a new axiom.
-pub struct LSystemIterator<T: Clone> {
-    current_state: Vec<T>,
-    rules: ProductionRule<T>,
-    zeroth: bool
+/// An iterator over an L-System, where each successive iteration applies the
+/// production rules to the current axiom to produce a new axiom. Built on
+/// [`std::iter::successors`] (see [`generations`]), seeded with the cloned
+/// axiom, so the "n = 0 returns the axiom" behavior falls out of the
+/// successor chain itself rather than a separate flag.
+pub type LSystemIterator<T> = Generations<Vec<T>>;
+
+fn apply_rules<T: Clone>(state: &[T], rules: &dyn Fn(T) -> Vec<T>) -> Vec<T> {
+    let mut new_state: Vec<T> = Vec::new();
+    for element in state.iter().cloned() {
+        new_state.extend(rules(element));
+    }
+    new_state
 }
 
-impl<T: Clone> Iterator for LSystemIterator<T> {
-    type Item = Vec<T>;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    fn next(&mut self) -> Option<Vec<T>> {
-        // In order to ensure that the "n = 0" case returns the original axiom,
-        // store whether we are in this state or not.
-        if self.zeroth {
-            self.zeroth = false;
-            return Some(self.current_state.clone())
-        }
+    #[derive(Clone, Debug, PartialEq)]
+    enum Algae {
+        A,
+        B,
+    }
 
-        // Otherwise, apply the production rules to the axiom to produce a new
-        // axiom for the iteration level.
-        let mut new_state: Vec<T> = Vec::new();
-        for element in self.current_state.iter().cloned() {
-            let entry = (self.rules)(element);
-            new_state.push_all(entry.as_slice());
+    fn algae_rule(input: Algae) -> Vec<Algae> {
+        match input {
+            Algae::A => vec![Algae::A, Algae::B],
+            Algae::B => vec![Algae::A],
         }
-        self.current_state = new_state;
-        Some(self.current_state.clone())
+    }
+
+    #[test]
+    fn nth_iteration_zero_returns_the_axiom_unchanged() {
+        let lsystem = LSystemType::new(vec![Algae::B], algae_rule);
+        assert_eq!(lsystem.nth_iteration(0), vec![Algae::B]);
+    }
+
+    #[test]
+    fn nth_iteration_matches_lindenmayers_algae_sequence() {
+        let lsystem = LSystemType::new(vec![Algae::B], algae_rule);
+        assert_eq!(
+            lsystem.nth_iteration(4),
+            vec![Algae::A, Algae::B, Algae::A, Algae::A, Algae::B]
+        );
+    }
+
+    #[test]
+    fn take_iterations_returns_n_plus_one_generations_ending_at_n() {
+        let lsystem = LSystemType::new(vec![Algae::B], algae_rule);
+        let generations = lsystem.take_iterations(4);
+
+        assert_eq!(generations.len(), 5);
+        assert_eq!(generations[0], vec![Algae::B]);
+        assert_eq!(generations[4], lsystem.nth_iteration(4));
     }
 }
@@ -0,0 +1,127 @@
+//! Parametric Lindenmayer Systems.
+//!
+//! To model continuous growth — internode lengths that increase over time,
+//! branching angles that shrink with depth — the alphabet needs to carry
+//! numeric parameters alongside each symbol, as in the parametric L-systems
+//! of Prusinkiewicz and Lindenmayer. Here a symbol is a `(T, Vec<f64>)` pair
+//! and a [`ParametricRule`] computes a successor's symbols *and* their
+//! parameters from the predecessor's symbol and parameters.
+//!
+//! A rule may return an empty `Vec` to mean "the guard condition isn't met,
+//! leave this symbol unchanged," which lets rules express conditional
+//! productions such as "only rewrite while length > threshold".
+//!
+//! ```ignore
+//! use lsystem::parametric::ParametricLSystemType;
+//!
+//! fn internode_rule(symbol: &char, params: &[f64]) -> Vec<(char, Vec<f64>)> {
+//!     let length = params[0];
+//!     if length <= 1.0 {
+//!         return Vec::new(); // guard not met: keep this symbol unchanged
+//!     }
+//!     vec!((*symbol, vec!(length / 2.0)), (*symbol, vec!(length / 2.0)))
+//! }
+//!
+//! let lsystem = ParametricLSystemType::new(vec!(('F', vec!(16.0))), internode_rule);
+//! ```
+
+use crate::generations::{self, Generations};
+
+/// A parametric production rule. Returns the successor symbols and their
+/// parameters; an empty `Vec` means the guard condition was not met and the
+/// predecessor should be kept unchanged.
+pub type ParametricRule<T> = fn(&T, &[f64]) -> Vec<(T, Vec<f64>)>;
+
+/// A Lindenmayer System whose alphabet pairs each symbol with a `Vec<f64>`
+/// of parameters that the rule can read and recompute on each rewrite.
+pub struct ParametricLSystemType<T: Clone> {
+    axiom: Vec<(T, Vec<f64>)>,
+    rules: ParametricRule<T>,
+}
+
+impl<T: Clone + 'static> ParametricLSystemType<T> {
+    pub fn new(axiom: Vec<(T, Vec<f64>)>, rules: ParametricRule<T>) -> ParametricLSystemType<T> {
+        ParametricLSystemType { axiom, rules }
+    }
+
+    /// Returns an iterator that yields successive iterations of the
+    /// L-System, starting with the axiom itself (`n = 0`). The iterator
+    /// never terminates, so any loop over it must be broken manually.
+    pub fn iter(&self) -> ParametricLSystemIterator<T> {
+        let rules = self.rules;
+        generations::generations(self.axiom.clone(), move |state: &Vec<(T, Vec<f64>)>| {
+            apply_rules(state, rules)
+        })
+    }
+
+    /// Directly returns generation `n` of the L-System, i.e. the axiom
+    /// rewritten `n` times.
+    pub fn nth_iteration(&self, n: usize) -> Vec<(T, Vec<f64>)> {
+        generations::nth_generation(self.iter(), n)
+    }
+
+    /// Returns generations `0` through `n` inclusive, as a `Vec` of `n + 1`
+    /// states.
+    pub fn take_iterations(&self, n: usize) -> Vec<Vec<(T, Vec<f64>)>> {
+        generations::take_generations(self.iter(), n)
+    }
+}
+
+/// An iterator over a [`ParametricLSystemType`], where each successive
+/// iteration applies the parametric rule to every `(symbol, params)` pair,
+/// keeping the predecessor unchanged whenever the rule returns no
+/// successors.
+pub type ParametricLSystemIterator<T> = Generations<Vec<(T, Vec<f64>)>>;
+
+fn apply_rules<T: Clone>(state: &[(T, Vec<f64>)], rules: ParametricRule<T>) -> Vec<(T, Vec<f64>)> {
+    let mut new_state: Vec<(T, Vec<f64>)> = Vec::new();
+    for (symbol, params) in state.iter().cloned() {
+        let successors = rules(&symbol, &params);
+        if successors.is_empty() {
+            new_state.push((symbol, params));
+        } else {
+            new_state.extend(successors);
+        }
+    }
+    new_state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Halves the length while it exceeds 1.0; otherwise the guard isn't met
+    // and the symbol is returned unchanged via the empty-Vec convention.
+    fn internode_rule(symbol: &char, params: &[f64]) -> Vec<(char, Vec<f64>)> {
+        let length = params[0];
+        if length <= 1.0 {
+            return Vec::new();
+        }
+        vec![(*symbol, vec![length / 2.0]), (*symbol, vec![length / 2.0])]
+    }
+
+    #[test]
+    fn guard_not_met_keeps_symbol_unchanged() {
+        let lsystem = ParametricLSystemType::new(vec![('F', vec![1.0])], internode_rule);
+        assert_eq!(lsystem.nth_iteration(1), vec![('F', vec![1.0])]);
+    }
+
+    #[test]
+    fn guard_met_splits_and_halves_params() {
+        let lsystem = ParametricLSystemType::new(vec![('F', vec![4.0])], internode_rule);
+
+        assert_eq!(
+            lsystem.nth_iteration(1),
+            vec![('F', vec![2.0]), ('F', vec![2.0])]
+        );
+        assert_eq!(
+            lsystem.nth_iteration(2),
+            vec![
+                ('F', vec![1.0]),
+                ('F', vec![1.0]),
+                ('F', vec![1.0]),
+                ('F', vec![1.0]),
+            ]
+        );
+    }
+}
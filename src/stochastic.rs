@@ -0,0 +1,199 @@
+//! Stochastic (non-deterministic) L-Systems.
+//!
+//! The plain [`ProductionRule`](../type.ProductionRule.html) is purely
+//! deterministic: every symbol always rewrites to the same successor. Many of
+//! the stochastic plant models studied by Prusinkiewicz and Lindenmayer
+//! instead assign several candidate successors to one predecessor, each with
+//! a relative probability, so that repeated generation of the "same" system
+//! yields varied structures.
+//!
+//! A [`StochasticRule`] returns a list of `(weight, successor)` pairs rather
+//! than a single successor. Weights do not need to be normalized; at each
+//! rewrite step a value is drawn uniformly from `[0, total_weight)` and the
+//! matching successor is chosen by walking the cumulative weights.
+//!
+//! ```ignore
+//! use lsystem::stochastic::StochasticLSystemType;
+//!
+//! fn algae_rule(input: Algae) -> Vec<(f64, Vec<Algae>)> {
+//!     match input {
+//!         Algae::A => vec!((0.75, vec!(Algae::A, Algae::B)), (0.25, vec!(Algae::B))),
+//!         Algae::B => vec!((1.0, vec!(Algae::A))),
+//!     }
+//! }
+//!
+//! let lsystem = StochasticLSystemType::new(vec!(Algae::B), algae_rule);
+//! let n4 = lsystem.nth_iteration_seeded(42, 4);
+//! ```
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::generations::{self, Generations};
+
+/// A stochastic production rule maps a predecessor to a list of `(weight,
+/// successor)` pairs. An empty list is treated as the identity production,
+/// i.e. the symbol maps to itself.
+pub type StochasticRule<T> = fn(T) -> Vec<(f64, Vec<T>)>;
+
+/// A Lindenmayer System whose rule draws one successor out of several
+/// weighted candidates at each rewrite, via a [`StochasticRule`] and a
+/// seedable random number generator, rather than a deterministic
+/// [`ProductionRule`](../type.ProductionRule.html).
+pub struct StochasticLSystemType<T: Clone> {
+    axiom: Vec<T>,
+    rules: StochasticRule<T>,
+}
+
+impl<T: Clone + 'static> StochasticLSystemType<T> {
+    pub fn new(axiom: Vec<T>, rules: StochasticRule<T>) -> StochasticLSystemType<T> {
+        StochasticLSystemType { axiom, rules }
+    }
+
+    /// Returns an iterator seeded from OS entropy, so successive runs will
+    /// generally differ. Use [`iter_seeded`](#method.iter_seeded) for
+    /// reproducible output.
+    pub fn iter(&self) -> StochasticLSystemIterator<T> {
+        self.iter_seeded(rand::thread_rng().gen())
+    }
+
+    /// Returns an iterator whose random draws are entirely determined by
+    /// `seed`, so the same seed always produces the same sequence of
+    /// generations.
+    pub fn iter_seeded(&self, seed: u64) -> StochasticLSystemIterator<T> {
+        let rules = self.rules;
+        let mut rng = StdRng::seed_from_u64(seed);
+        generations::generations(self.axiom.clone(), move |state: &Vec<T>| {
+            apply_rules(state, rules, &mut rng)
+        })
+    }
+
+    /// Directly returns generation `n`, seeded from OS entropy. Use
+    /// [`nth_iteration_seeded`](#method.nth_iteration_seeded) for
+    /// reproducible output.
+    pub fn nth_iteration(&self, n: usize) -> Vec<T> {
+        generations::nth_generation(self.iter(), n)
+    }
+
+    /// Directly returns generation `n` of the run determined by `seed`.
+    pub fn nth_iteration_seeded(&self, seed: u64, n: usize) -> Vec<T> {
+        generations::nth_generation(self.iter_seeded(seed), n)
+    }
+
+    /// Returns generations `0` through `n` inclusive, seeded from OS
+    /// entropy. Use [`take_iterations_seeded`](#method.take_iterations_seeded)
+    /// for reproducible output.
+    pub fn take_iterations(&self, n: usize) -> Vec<Vec<T>> {
+        generations::take_generations(self.iter(), n)
+    }
+
+    /// Returns generations `0` through `n` inclusive of the run determined
+    /// by `seed`.
+    pub fn take_iterations_seeded(&self, seed: u64, n: usize) -> Vec<Vec<T>> {
+        generations::take_generations(self.iter_seeded(seed), n)
+    }
+}
+
+/// An iterator over a [`StochasticLSystemType`], where each successive
+/// iteration applies the stochastic rules to the current axiom, drawing a
+/// fresh random successor for every symbol.
+pub type StochasticLSystemIterator<T> = Generations<Vec<T>>;
+
+fn apply_rules<T: Clone>(state: &[T], rules: StochasticRule<T>, rng: &mut StdRng) -> Vec<T> {
+    let mut new_state: Vec<T> = Vec::new();
+    for element in state.iter().cloned() {
+        let successors = rules(element.clone());
+        new_state.extend(choose_successor(successors, element, rng));
+    }
+    new_state
+}
+
+/// Picks one of `successors` by a cumulative-weight walk, scaling a uniform
+/// draw to the (possibly un-normalized) sum of the weights. A zero or
+/// negative total weight, or an empty successor list, falls back to the
+/// identity production `identity`. The last bucket is always selected once
+/// the walk reaches it, which guards against floating-point rounding leaving
+/// a residual draw past the final cumulative weight.
+fn choose_successor<T>(successors: Vec<(f64, Vec<T>)>, identity: T, rng: &mut StdRng) -> Vec<T> {
+    if successors.is_empty() {
+        return vec![identity];
+    }
+
+    let total: f64 = successors.iter().map(|&(weight, _)| weight).sum();
+    if total <= 0.0 {
+        return vec![identity];
+    }
+
+    let draw = rng.gen::<f64>() * total;
+    let last = successors.len() - 1;
+    let mut cumulative = 0.0;
+    for (index, (weight, successor)) in successors.into_iter().enumerate() {
+        cumulative += weight;
+        if draw <= cumulative || index == last {
+            return successor;
+        }
+    }
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cumulative_walk_only_picks_listed_successors_and_reaches_each() {
+        let successors = vec![(1.0, vec!['a']), (1.0, vec!['b']), (1.0, vec!['c'])];
+
+        // Sample the walk with 50 differently seeded RNGs: every outcome
+        // must be one of the three successors, and all three must be
+        // reachable.
+        let mut seen = std::collections::HashSet::new();
+        for seed in 0..50u64 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let result = choose_successor(successors.clone(), 'x', &mut rng);
+            assert_eq!(result.len(), 1);
+            seen.insert(result[0]);
+        }
+        assert_eq!(seen, ['a', 'b', 'c'].into_iter().collect());
+    }
+
+    #[test]
+    fn last_bucket_wins_when_draw_reaches_the_total() {
+        let successors = vec![(1.0, vec!['a'])];
+        let mut rng = StdRng::seed_from_u64(0);
+
+        // A single full-weight bucket must always be chosen, even though
+        // floating-point rounding could in principle push `draw` a hair past
+        // `cumulative` before the comparison.
+        assert_eq!(choose_successor(successors, 'x', &mut rng), vec!['a']);
+    }
+
+    #[test]
+    fn zero_weight_falls_back_to_identity() {
+        let successors: Vec<(f64, Vec<char>)> = vec![(0.0, vec!['a']), (0.0, vec!['b'])];
+        let mut rng = StdRng::seed_from_u64(0);
+
+        assert_eq!(choose_successor(successors, 'x', &mut rng), vec!['x']);
+    }
+
+    #[test]
+    fn empty_successor_list_falls_back_to_identity() {
+        let mut rng = StdRng::seed_from_u64(0);
+        assert_eq!(choose_successor(Vec::new(), 'x', &mut rng), vec!['x']);
+    }
+
+    #[test]
+    fn iter_seeded_is_reproducible_for_the_same_seed() {
+        fn rule(input: char) -> Vec<(f64, Vec<char>)> {
+            match input {
+                'A' => vec![(0.75, vec!['A', 'B']), (0.25, vec!['B'])],
+                _ => vec![(1.0, vec!['A'])],
+            }
+        }
+
+        let lsystem = StochasticLSystemType::new(vec!['A'], rule);
+        let first: Vec<Vec<char>> = lsystem.iter_seeded(42).take(5).collect();
+        let second: Vec<Vec<char>> = lsystem.iter_seeded(42).take(5).collect();
+        assert_eq!(first, second);
+    }
+}
@@ -0,0 +1,89 @@
+//! A builder for the classic char-alphabet formulation of L-systems (e.g.
+//! `'A' -> "AB"`), without having to hand-write an enum and a `match`
+//! function.
+//!
+//! [`from_rules`] compiles a slice of `(predecessor, successor)` pairs into a
+//! lookup table and returns an [`LSystemType<char>`](../struct.LSystemType.html)
+//! built via [`LSystemType::from_boxed_rule`](../struct.LSystemType.html#method.from_boxed_rule),
+//! so each `LSystemType<char>` owns its own table rather than sharing one
+//! with every other system built this way. A symbol with no entry in the
+//! table defaults to the identity production, making it a terminal/constant
+//! symbol (like the `+`, `-`, `[`, `]` of a turtle-graphics alphabet).
+//!
+//! ```ignore
+//! use lsystem::strings::from_rules;
+//!
+//! let koch = from_rules("F", &[('F', "F+F-F-F+F")]);
+//! let n2 = koch.nth_iteration(2);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use crate::LSystemType;
+
+/// Builds an `LSystemType<char>` whose rules are `rules`, compiled into a
+/// lookup table owned by the returned system, and whose axiom is `axiom`.
+/// Predecessors not present in `rules` are left unchanged by the resulting
+/// system.
+pub fn from_rules(axiom: &str, rules: &[(char, &str)]) -> LSystemType<char> {
+    let table: HashMap<char, Vec<char>> = rules
+        .iter()
+        .map(|&(predecessor, successor)| (predecessor, successor.chars().collect()))
+        .collect();
+
+    LSystemType::from_boxed_rule(axiom.chars().collect(), move |symbol: char| {
+        table.get(&symbol).cloned().unwrap_or_else(|| vec![symbol])
+    })
+}
+
+/// The symbols that appear as the successor of some rule but never as a
+/// predecessor — i.e. the draw-only constants of the alphabet, such as a
+/// turtle interpreter's `+`, `-`, `[`, `]`.
+pub fn terminals(rules: &[(char, &str)]) -> HashSet<char> {
+    let predecessors: HashSet<char> = rules.iter().map(|&(predecessor, _)| predecessor).collect();
+    rules
+        .iter()
+        .flat_map(|&(_, successor)| successor.chars())
+        .filter(|symbol| !predecessors.contains(symbol))
+        .collect()
+}
+
+/// The symbols that appear as the predecessor of some rule, i.e. the symbols
+/// that actually get rewritten.
+pub fn non_terminals(rules: &[(char, &str)]) -> HashSet<char> {
+    rules.iter().map(|&(predecessor, _)| predecessor).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KOCH_RULES: &[(char, &str)] = &[('F', "F+F-F-F+F")];
+
+    #[test]
+    fn from_rules_rewrites_known_predecessors_and_leaves_others_unchanged() {
+        let koch = from_rules("F+", KOCH_RULES);
+        assert_eq!(koch.nth_iteration(1).into_iter().collect::<String>(), "F+F-F-F+F+");
+    }
+
+    #[test]
+    fn two_systems_built_from_rules_do_not_share_state() {
+        let koch = from_rules("F", KOCH_RULES);
+        let sierpinski = from_rules("F-G-G", &[('F', "F-G+F+G-F"), ('G', "GG")]);
+
+        // Iterating one system must not disturb the other's productions.
+        let koch_n1 = koch.nth_iteration(1);
+        let _ = sierpinski.nth_iteration(1);
+        assert_eq!(koch.nth_iteration(1), koch_n1);
+    }
+
+    #[test]
+    fn terminals_are_symbols_that_only_ever_appear_as_successors() {
+        assert_eq!(terminals(KOCH_RULES), ['+', '-'].into_iter().collect());
+    }
+
+    #[test]
+    fn non_terminals_are_the_rule_predecessors() {
+        assert_eq!(non_terminals(KOCH_RULES), ['F'].into_iter().collect());
+    }
+}
@@ -0,0 +1,183 @@
+//! Turtle-graphics interpreter for L-System output.
+//!
+//! Rewriting a string is only half the appeal of an L-System — the real
+//! payoff is translating the generated symbols into geometry, as with the
+//! Koch curve, the Sierpinski triangle, or a branching plant. This module
+//! walks a `Vec<T>` produced by an [`LSystemIterator`](../type.LSystemIterator.html)
+//! (or any of the other iterators in this crate) and maps each symbol to a
+//! [`TurtleCommand`] via a user-supplied function, accumulating the line
+//! segments a turtle would trace out.
+//!
+//! ```ignore
+//! use lsystem::turtle::{Turtle, TurtleCommand};
+//!
+//! fn koch_command(symbol: &char) -> TurtleCommand {
+//!     match *symbol {
+//!         'F' => TurtleCommand::Forward(1.0),
+//!         '+' => TurtleCommand::Turn(60.0),
+//!         '-' => TurtleCommand::Turn(-60.0),
+//!         _ => TurtleCommand::Move(0.0),
+//!     }
+//! }
+//!
+//! let mut turtle = Turtle::new();
+//! turtle.interpret(&['F', '+', 'F', '-', 'F'], koch_command);
+//! let svg = turtle.to_svg();
+//! ```
+
+/// A single instruction understood by the [`Turtle`]. Angles are in
+/// degrees; a positive `Turn` rotates counter-clockwise.
+pub enum TurtleCommand {
+    /// Move forward, drawing a line segment along the way.
+    Forward(f64),
+    /// Move forward without drawing (a "pen up" move).
+    Move(f64),
+    /// Rotate the current heading by the given number of degrees.
+    Turn(f64),
+    /// Push the current position and heading onto the state stack, for
+    /// later branching.
+    Push,
+    /// Pop the most recently pushed position and heading, restoring them
+    /// as the turtle's current state.
+    Pop,
+}
+
+/// A single drawn segment, from `from` to `to`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LineSegment {
+    pub from: (f64, f64),
+    pub to: (f64, f64),
+}
+
+/// Interprets a sequence of [`TurtleCommand`]s, tracking position, heading,
+/// and a stack of saved states used by `Push`/`Pop` to support branching
+/// structures.
+pub struct Turtle {
+    x: f64,
+    y: f64,
+    heading: f64,
+    stack: Vec<(f64, f64, f64)>,
+    segments: Vec<LineSegment>,
+}
+
+impl Default for Turtle {
+    fn default() -> Turtle {
+        Turtle::new()
+    }
+}
+
+impl Turtle {
+    /// Creates a turtle at the origin, facing along the positive x-axis
+    /// (heading `0.0` degrees), with an empty stack and no segments drawn.
+    pub fn new() -> Turtle {
+        Turtle { x: 0.0, y: 0.0, heading: 0.0, stack: Vec::new(), segments: Vec::new() }
+    }
+
+    /// Maps each symbol in `symbols` to a [`TurtleCommand`] via
+    /// `command_for` and executes the resulting commands in order.
+    pub fn interpret<T>(&mut self, symbols: &[T], command_for: fn(&T) -> TurtleCommand) {
+        for symbol in symbols {
+            match command_for(symbol) {
+                TurtleCommand::Forward(distance) => {
+                    let next = self.advance(distance);
+                    self.segments.push(LineSegment { from: (self.x, self.y), to: next });
+                    self.x = next.0;
+                    self.y = next.1;
+                }
+                TurtleCommand::Move(distance) => {
+                    let next = self.advance(distance);
+                    self.x = next.0;
+                    self.y = next.1;
+                }
+                TurtleCommand::Turn(degrees) => self.heading += degrees,
+                TurtleCommand::Push => self.stack.push((self.x, self.y, self.heading)),
+                TurtleCommand::Pop => {
+                    if let Some((x, y, heading)) = self.stack.pop() {
+                        self.x = x;
+                        self.y = y;
+                        self.heading = heading;
+                    }
+                }
+            }
+        }
+    }
+
+    fn advance(&self, distance: f64) -> (f64, f64) {
+        let radians = self.heading.to_radians();
+        (self.x + distance * radians.cos(), self.y + distance * radians.sin())
+    }
+
+    /// The line segments traced so far, in the order they were drawn.
+    pub fn segments(&self) -> &[LineSegment] {
+        &self.segments
+    }
+
+    /// Serializes the traced segments as a minimal standalone SVG document
+    /// containing a single `<path>` element.
+    pub fn to_svg(&self) -> String {
+        let mut data = String::new();
+        for segment in &self.segments {
+            data.push_str(&format!(
+                "M{:.3},{:.3} L{:.3},{:.3} ",
+                segment.from.0, segment.from.1, segment.to.0, segment.to.1
+            ));
+        }
+
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\"><path d=\"{}\" fill=\"none\" stroke=\"black\"/></svg>",
+            data.trim_end()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn right_angle_command(symbol: &char) -> TurtleCommand {
+        match *symbol {
+            'F' => TurtleCommand::Forward(1.0),
+            '+' => TurtleCommand::Turn(90.0),
+            '[' => TurtleCommand::Push,
+            ']' => TurtleCommand::Pop,
+            _ => TurtleCommand::Move(0.0),
+        }
+    }
+
+    #[test]
+    fn forward_traces_a_segment_along_the_heading() {
+        let mut turtle = Turtle::new();
+        turtle.interpret(&['F'], right_angle_command);
+
+        let segments = turtle.segments();
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].from, (0.0, 0.0));
+        assert!((segments[0].to.0 - 1.0).abs() < 1e-9);
+        assert!(segments[0].to.1.abs() < 1e-9);
+    }
+
+    #[test]
+    fn push_and_pop_restore_position_for_branching() {
+        let mut turtle = Turtle::new();
+        turtle.interpret(&['F', '[', '+', 'F', ']', 'F'], right_angle_command);
+
+        // The branch (inside the brackets) turns 90 degrees and draws a
+        // segment upward; Pop should restore the pre-branch state so the
+        // trailing F continues straight along the original heading.
+        let segments = turtle.segments();
+        assert_eq!(segments.len(), 3);
+        assert!((segments[2].to.0 - 2.0).abs() < 1e-9);
+        assert!(segments[2].to.1.abs() < 1e-9);
+    }
+
+    #[test]
+    fn to_svg_embeds_a_move_and_line_command_per_segment() {
+        let mut turtle = Turtle::new();
+        turtle.interpret(&['F', 'F'], right_angle_command);
+
+        let svg = turtle.to_svg();
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches('M').count(), 2);
+        assert_eq!(svg.matches('L').count(), 2);
+    }
+}